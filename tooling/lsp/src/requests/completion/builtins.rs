@@ -1,5 +1,8 @@
 use async_lsp::lsp_types::CompletionItemKind;
-use noirc_frontend::{ast::AttributeTarget, elaborator::PrimitiveType, token::Keyword};
+use noirc_frontend::{
+    ast::AttributeTarget, elaborator::PrimitiveType, hir_def::types::Type, lints::Lint,
+    token::Keyword,
+};
 use strum::IntoEnumIterator;
 
 use super::{
@@ -10,6 +13,7 @@ use super::{
     },
     kinds::FunctionCompletionKind,
     name_matches,
+    relevance::CompletionRelevance,
 };
 
 impl NodeFinder<'_> {
@@ -35,42 +39,62 @@ impl NodeFinder<'_> {
                         }
                     }
 
-                    self.completion_items.push(
-                        completion_item_with_trigger_parameter_hints_command(
-                            snippet_completion_item(
-                                label,
-                                CompletionItemKind::FUNCTION,
-                                insert_text,
-                                description,
-                            ),
+                    let mut item = completion_item_with_trigger_parameter_hints_command(
+                        snippet_completion_item(
+                            label,
+                            CompletionItemKind::FUNCTION,
+                            insert_text,
+                            description,
                         ),
                     );
+                    let relevance = CompletionRelevance {
+                        exact_name_match: func.name == prefix,
+                        ..Default::default()
+                    };
+                    item.sort_text = Some(relevance.sort_text(func.name));
+                    self.completion_items.push(item);
                 }
             }
         }
     }
 
-    pub(super) fn builtin_values_completion(&mut self, prefix: &str) {
+    pub(super) fn builtin_values_completion(&mut self, prefix: &str, expected_type: Option<&Type>) {
+        let expects_bool = matches!(expected_type, Some(Type::Bool));
+
         for keyword in ["false", "true"] {
             if name_matches(keyword, prefix) {
-                self.completion_items.push(simple_completion_item(
+                let mut item = simple_completion_item(
                     keyword,
                     CompletionItemKind::KEYWORD,
                     Some("bool".to_string()),
-                ));
+                );
+                let relevance = CompletionRelevance {
+                    exact_type_match: expects_bool,
+                    exact_name_match: keyword == prefix,
+                    ..Default::default()
+                };
+                item.sort_text = Some(relevance.sort_text(keyword));
+                self.completion_items.push(item);
             }
         }
     }
 
-    pub(super) fn builtin_types_completion(&mut self, prefix: &str) {
+    pub(super) fn builtin_types_completion(&mut self, prefix: &str, type_position: bool) {
         for primitive_type in PrimitiveType::iter() {
             let name = primitive_type.name();
             if name_matches(name, prefix) {
-                self.completion_items.push(simple_completion_item(
+                let mut item = simple_completion_item(
                     name,
                     CompletionItemKind::STRUCT,
                     Some(name.to_string()),
-                ));
+                );
+                let relevance = CompletionRelevance {
+                    exact_type_match: type_position,
+                    exact_name_match: name == prefix,
+                    ..Default::default()
+                };
+                item.sort_text = Some(relevance.sort_text(name));
+                self.completion_items.push(item);
             }
         }
     }
@@ -79,15 +103,15 @@ impl NodeFinder<'_> {
         match target {
             AttributeTarget::Module => (),
             AttributeTarget::Trait => {
-                self.suggest_allow("dead_code", prefix);
+                self.suggest_allow(prefix, target);
             }
             AttributeTarget::Struct => {
                 self.suggest_one_argument_attributes(prefix, &["abi"]);
-                self.suggest_allow("dead_code", prefix);
+                self.suggest_allow(prefix, target);
             }
             AttributeTarget::Enum => {
                 self.suggest_one_argument_attributes(prefix, &["abi"]);
-                self.suggest_allow("dead_code", prefix);
+                self.suggest_allow(prefix, target);
             }
             AttributeTarget::Function => {
                 let no_arguments_attributes = &[
@@ -160,25 +184,58 @@ impl NodeFinder<'_> {
                     ));
                 }
 
-                self.suggest_allow("dead_code", prefix);
+                self.suggest_allow(prefix, target);
             }
             AttributeTarget::Let => {
-                self.suggest_allow("unused_variables", prefix);
+                self.suggest_allow(prefix, target);
+            }
+        }
+    }
+
+    /// Suggests `allow(lint_name)` for every lint in the compiler's lint
+    /// registry that's applicable to `target`, rather than a hardcoded list
+    /// per `AttributeTarget` arm. New lints only need to declare which
+    /// targets they apply to in [`Lint`] itself to show up here.
+    fn suggest_allow(&mut self, prefix: &str, target: AttributeTarget) {
+        for lint in Lint::iter() {
+            if !lint.applies_to(target) {
+                continue;
+            }
+
+            if name_matches("allow", prefix) || name_matches(lint.name(), prefix) {
+                self.completion_items.push(simple_completion_item(
+                    format!("allow({})", lint.name()),
+                    CompletionItemKind::METHOD,
+                    Some(lint.description().to_string()),
+                ));
             }
         }
     }
 
-    fn suggest_allow(&mut self, name: &'static str, prefix: &str) {
-        if name_matches("allow", prefix) || name_matches(name, prefix) {
+    /// Completes lint names inside an `allow(..)` / `warn(..)` / `deny(..)`
+    /// attribute argument, e.g. `#[allow(dead_)]` offers `dead_code`.
+    pub(super) fn suggest_lint_names(&mut self, prefix: &str) {
+        let all_lints = Lint::iter().map(|lint| (lint.name(), lint.description())).collect::<Vec<_>>();
+        for (name, description) in lint_names_matching(&all_lints, prefix) {
             self.completion_items.push(simple_completion_item(
-                format!("allow({name})"),
-                CompletionItemKind::METHOD,
-                None,
+                name,
+                CompletionItemKind::ENUM_MEMBER,
+                Some(description.to_string()),
             ));
         }
     }
 }
 
+/// Filters `(name, description)` lint pairs by prefix, kept separate from
+/// [`Lint`] itself so the filtering logic can be unit tested without
+/// depending on the compiler's actual lint registry.
+fn lint_names_matching<'a>(
+    lints: &[(&'a str, &'a str)],
+    prefix: &str,
+) -> Vec<(&'a str, &'a str)> {
+    lints.iter().copied().filter(|(name, _)| name_matches(name, prefix)).collect()
+}
+
 pub(super) struct BuiltInFunction {
     pub(super) name: &'static str,
     pub(super) parameters: &'static str,
@@ -236,3 +293,21 @@ pub(super) fn keyword_builtin_function(keyword: &Keyword) -> Option<BuiltInFunct
         | Keyword::While => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_lints_by_prefix() {
+        let lints = [("dead_code", "unused item"), ("unused_variables", "unused binding")];
+        let matches = lint_names_matching(&lints, "dead");
+        assert_eq!(matches, vec![("dead_code", "unused item")]);
+    }
+
+    #[test]
+    fn empty_prefix_matches_every_lint() {
+        let lints = [("dead_code", "unused item"), ("unused_variables", "unused binding")];
+        assert_eq!(lint_names_matching(&lints, "").len(), 2);
+    }
+}
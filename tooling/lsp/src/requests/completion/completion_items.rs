@@ -0,0 +1,69 @@
+use async_lsp::lsp_types::{
+    Command, CompletionItem, CompletionItemKind, CompletionTextEdit, InsertTextFormat, Range,
+};
+
+pub(super) fn simple_completion_item(
+    label: impl Into<String>,
+    kind: CompletionItemKind,
+    detail: Option<String>,
+) -> CompletionItem {
+    CompletionItem {
+        label: label.into(),
+        kind: Some(kind),
+        detail,
+        ..CompletionItem::default()
+    }
+}
+
+pub(super) fn snippet_completion_item(
+    label: impl Into<String>,
+    kind: CompletionItemKind,
+    insert_text: impl Into<String>,
+    detail: Option<String>,
+) -> CompletionItem {
+    CompletionItem {
+        label: label.into(),
+        kind: Some(kind),
+        detail,
+        insert_text: Some(insert_text.into()),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..CompletionItem::default()
+    }
+}
+
+/// A snippet completion whose acceptance replaces `range` (rather than just
+/// inserting at the cursor), for completions that rewrite text already on
+/// the line instead of extending the typed prefix.
+pub(super) fn snippet_completion_item_replacing(
+    label: impl Into<String>,
+    kind: CompletionItemKind,
+    range: Range,
+    new_text: impl Into<String>,
+    detail: Option<String>,
+) -> CompletionItem {
+    CompletionItem {
+        label: label.into(),
+        kind: Some(kind),
+        detail,
+        text_edit: Some(CompletionTextEdit::Edit(async_lsp::lsp_types::TextEdit {
+            range,
+            new_text: new_text.into(),
+        })),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..CompletionItem::default()
+    }
+}
+
+/// Wraps a completion item so that accepting it immediately triggers the
+/// editor's parameter-hints popup, since the item's snippet left the
+/// cursor inside a parameter list.
+pub(super) fn completion_item_with_trigger_parameter_hints_command(
+    mut item: CompletionItem,
+) -> CompletionItem {
+    item.command = Some(Command {
+        title: "Trigger parameter hints".to_string(),
+        command: "editor.action.triggerParameterHints".to_string(),
+        arguments: None,
+    });
+    item
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use async_lsp::lsp_types::{CompletionItemKind, TextEdit};
+
+use super::{
+    NodeFinder, completion_items::simple_completion_item, name_matches, relevance::CompletionRelevance,
+};
+
+/// A definition discovered while walking the crate graph, before it's been
+/// deduplicated into the flyimport index.
+pub(super) struct CrateItem {
+    pub(super) name: &'static str,
+    pub(super) module_path: &'static str,
+    pub(super) kind: CompletionItemKind,
+}
+
+/// An item that's visible somewhere in the crate graph but not yet imported
+/// into the current module.
+pub(super) struct UnimportedItem {
+    pub(super) name: &'static str,
+    pub(super) module_path: &'static str,
+    pub(super) kind: CompletionItemKind,
+}
+
+/// Builds the short-name -> fully-qualified-path index flyimport
+/// completions are served from, deduplicating items that were reached
+/// more than once through the same path. The same name under different
+/// paths (e.g. a re-export) is kept as separate entries.
+pub(super) fn build_unimported_index(crate_items: &[CrateItem]) -> Vec<UnimportedItem> {
+    let mut seen = HashSet::new();
+    let mut index = Vec::new();
+
+    for item in crate_items {
+        if !seen.insert((item.name, item.module_path)) {
+            continue;
+        }
+        index.push(UnimportedItem { name: item.name, module_path: item.module_path, kind: item.kind });
+    }
+
+    index
+}
+
+impl NodeFinder<'_> {
+    /// Local items that match `prefix` exactly are expected to already have
+    /// been pushed to `self.completion_items` by the regular name-completion
+    /// path and are ranked above these via `sort_text`, so this only needs
+    /// to consider items that aren't already in scope.
+    pub(super) fn flyimport_completion(
+        &mut self,
+        prefix: &str,
+        unimported_items: &[UnimportedItem],
+        names_in_scope: &HashSet<&str>,
+    ) {
+        for item in unimported_items {
+            if names_in_scope.contains(item.name) {
+                continue;
+            }
+
+            if !name_matches(item.name, prefix) {
+                continue;
+            }
+
+            let use_path = format!("use {};\n", item.module_path);
+
+            let mut completion_item = simple_completion_item(
+                item.name,
+                item.kind,
+                Some(item.module_path.to_string()),
+            );
+            completion_item.additional_text_edits = Some(vec![TextEdit {
+                range: self.use_insertion_range(),
+                new_text: use_path,
+            }]);
+            // `is_local` is left unset, so an item that sets it and matches
+            // the prefix at least as closely outranks these.
+            let relevance =
+                CompletionRelevance { exact_name_match: item.name == prefix, ..Default::default() };
+            completion_item.sort_text = Some(relevance.sort_text(item.name));
+
+            self.completion_items.push(completion_item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &'static str, module_path: &'static str) -> CrateItem {
+        CrateItem { name, module_path, kind: CompletionItemKind::FUNCTION }
+    }
+
+    #[test]
+    fn dedupes_items_reached_through_the_same_path_twice() {
+        let crate_items = vec![item("foo", "crate::bar::foo"), item("foo", "crate::bar::foo")];
+        let index = build_unimported_index(&crate_items);
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn keeps_same_name_reexported_from_different_paths() {
+        let crate_items = vec![item("foo", "crate::bar::foo"), item("foo", "crate::baz::foo")];
+        let index = build_unimported_index(&crate_items);
+        assert_eq!(index.len(), 2);
+    }
+}
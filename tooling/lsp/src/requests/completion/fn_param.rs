@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use async_lsp::lsp_types::CompletionItemKind;
+use noirc_frontend::ast::{NoirFunction, Pattern};
+
+use super::{NodeFinder, completion_items::snippet_completion_item, name_matches};
+
+/// Ranks `(name, type)` parameter pairs by how often each occurs, most
+/// frequent first, keeping first-seen order among ties.
+fn rank_param_pairs(pairs: Vec<(String, String)>) -> Vec<(String, String, usize)> {
+    let mut frequency: HashMap<(String, String), usize> = HashMap::new();
+    let mut order = Vec::new();
+
+    for pair in pairs {
+        if !frequency.contains_key(&pair) {
+            order.push(pair.clone());
+        }
+        *frequency.entry(pair).or_insert(0) += 1;
+    }
+
+    order.sort_by_key(|pair| std::cmp::Reverse(frequency[pair]));
+    order.into_iter().map(|(name, typ)| {
+        let count = frequency[&(name.clone(), typ.clone())];
+        (name, typ, count)
+    }).collect()
+}
+
+impl NodeFinder<'_> {
+    /// Completes `name: Type` pairs harvested from the parameters of other
+    /// visible `fn` definitions, ranked by frequency.
+    pub(super) fn fn_param_completion(&mut self, visible_functions: &[NoirFunction], prefix: &str) {
+        let pairs = visible_functions
+            .iter()
+            .flat_map(|function| &function.def.parameters)
+            .filter_map(|param| match &param.pattern {
+                Pattern::Identifier(ident) => Some((ident.0.contents.to_string(), param.typ.to_string())),
+                _ => None,
+            })
+            .collect();
+
+        for (name, typ, count) in rank_param_pairs(pairs) {
+            if !name_matches(&name, prefix) {
+                continue;
+            }
+
+            self.completion_items.push(snippet_completion_item(
+                format!("{name}: {typ}"),
+                CompletionItemKind::VARIABLE,
+                format!("{name}: {typ}"),
+                Some(format!("used {count} times")),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_by_frequency_descending() {
+        let pairs = vec![
+            ("self".to_string(), "Self".to_string()),
+            ("context".to_string(), "&mut Context".to_string()),
+            ("self".to_string(), "Self".to_string()),
+        ];
+        let ranked = rank_param_pairs(pairs);
+        assert_eq!(ranked[0], ("self".to_string(), "Self".to_string(), 2));
+        assert_eq!(ranked[1], ("context".to_string(), "&mut Context".to_string(), 1));
+    }
+
+    #[test]
+    fn keeps_first_seen_order_among_ties() {
+        let pairs = vec![("a".to_string(), "Field".to_string()), ("b".to_string(), "Field".to_string())];
+        let ranked = rank_param_pairs(pairs);
+        assert_eq!(ranked[0].0, "a");
+        assert_eq!(ranked[1].0, "b");
+    }
+}
@@ -0,0 +1,7 @@
+/// How a function name should be completed: just the name, or the name
+/// plus a parameter snippet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FunctionCompletionKind {
+    Name,
+    NameAndParameters,
+}
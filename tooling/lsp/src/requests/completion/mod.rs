@@ -0,0 +1,149 @@
+mod builtins;
+mod completion_items;
+mod flyimport;
+mod fn_param;
+mod kinds;
+mod postfix;
+mod relevance;
+mod trait_impl;
+
+use std::collections::HashSet;
+
+use async_lsp::lsp_types::{CompletionItem, Position, Range};
+use noirc_errors::Span;
+use noirc_frontend::{ast::NoirFunction, hir_def::traits::Trait, hir_def::types::Type};
+
+use self::{flyimport::CrateItem, kinds::FunctionCompletionKind};
+
+/// Where the cursor is and what kind of completion is being requested.
+/// `find_completions_at` dispatches on this to decide which of the
+/// completion sources in this module to run.
+pub(crate) enum CompletionContext<'a> {
+    /// The cursor is right after `<receiver>.` and `prefix` is what's been
+    /// typed of the postfix keyword so far, e.g. `foo().if`.
+    AfterDot { receiver_text: &'a str, receiver_and_method_span: Span },
+    /// The cursor is completing a plain identifier, e.g. an expression or
+    /// the start of a path. `crate_items` is a fresh scan of the crate
+    /// graph for flyimport candidates, `names_in_scope` is what's already
+    /// bound or imported in the current module, `expected_type` is the
+    /// *value* type inferred at the cursor (assignment RHS, argument,
+    /// return expression) if any, and `type_position` is whether the
+    /// cursor is itself typing a type annotation rather than a value
+    /// (those are mutually exclusive: a type annotation has no expected
+    /// value type, and a value position is never completing a type name).
+    Name {
+        crate_items: &'a [CrateItem],
+        names_in_scope: &'a HashSet<&'a str>,
+        expected_type: Option<&'a Type>,
+        type_position: bool,
+    },
+    /// The cursor is at item position inside an `impl <trait_> for .. { .. }`
+    /// body; `already_implemented` are the names already present in it.
+    ImplTraitBody { trait_: &'a Trait, already_implemented: &'a [String] },
+    /// The cursor is typing a parameter in a function signature, e.g.
+    /// `fn f(a: Field, <here>)`.
+    FnParam { visible_functions: &'a [NoirFunction] },
+    /// The cursor is inside the argument list of an `allow(..)` / `warn(..)`
+    /// / `deny(..)` attribute.
+    AttributeArgument,
+}
+
+pub(crate) struct NodeFinder<'a> {
+    source: &'a str,
+    byte_index: usize,
+    pub(crate) completion_items: Vec<CompletionItem>,
+}
+
+impl<'a> NodeFinder<'a> {
+    pub(crate) fn new(source: &'a str, byte_index: usize) -> Self {
+        Self { source, byte_index, completion_items: Vec::new() }
+    }
+
+    pub(crate) fn find_completions_at(&mut self, context: CompletionContext<'_>, prefix: &str) {
+        match context {
+            CompletionContext::AfterDot { receiver_text, receiver_and_method_span } => {
+                self.postfix_completion(receiver_text, receiver_and_method_span, prefix);
+            }
+            CompletionContext::Name { crate_items, names_in_scope, expected_type, type_position } => {
+                self.builtin_functions_completion(prefix, FunctionCompletionKind::NameAndParameters);
+                self.builtin_values_completion(prefix, expected_type);
+                self.builtin_types_completion(prefix, type_position);
+                let unimported_items = flyimport::build_unimported_index(crate_items);
+                self.flyimport_completion(prefix, &unimported_items, names_in_scope);
+            }
+            CompletionContext::ImplTraitBody { trait_, already_implemented } => {
+                self.trait_impl_completion(trait_, already_implemented);
+            }
+            CompletionContext::FnParam { visible_functions } => {
+                self.fn_param_completion(visible_functions, prefix);
+            }
+            CompletionContext::AttributeArgument => {
+                self.suggest_lint_names(prefix);
+            }
+        }
+    }
+
+    /// The range at which a new `use` statement should be inserted: right
+    /// after the current module's existing top-level `use` block, or at
+    /// the top of the file if it has none. Stops at the first top-level
+    /// item that isn't a `use`/blank/comment line, so a trailing
+    /// `#[cfg(test)] mod tests { use super::*; .. }` doesn't get mistaken
+    /// for part of the module's own `use` block.
+    fn use_insertion_range(&self) -> Range {
+        let mut insertion_line = 0;
+        let mut in_use_statement = false;
+
+        for (index, line) in self.source.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if in_use_statement || trimmed.starts_with("use ") {
+                // A `use` group may span several lines; it ends at its `;`.
+                in_use_statement = !trimmed.contains(';');
+                if !in_use_statement {
+                    insertion_line = index as u32 + 1;
+                }
+            } else if trimmed.is_empty() || trimmed.starts_with("//") {
+                continue;
+            } else {
+                break;
+            }
+        }
+
+        let position = Position { line: insertion_line, character: 0 };
+        Range { start: position, end: position }
+    }
+
+    fn span_to_range(&self, span: Span) -> Range {
+        let start = self.byte_index_to_position(span.start() as usize);
+        let end = self.byte_index_to_position(span.end() as usize);
+        Range { start, end }
+    }
+
+    fn byte_index_to_position(&self, byte_index: usize) -> Position {
+        let prefix = &self.source[..byte_index.min(self.source.len())];
+        let line = prefix.matches('\n').count() as u32;
+        let character = match prefix.rfind('\n') {
+            Some(newline_index) => (prefix.len() - newline_index - 1) as u32,
+            None => prefix.len() as u32,
+        };
+        Position { line, character }
+    }
+}
+
+/// Whether `name` is a candidate completion for what the user has typed so
+/// far. Case-insensitive prefix match, consistent with the rest of the
+/// completion items in this module.
+pub(super) fn name_matches(name: &str, prefix: &str) -> bool {
+    name.to_lowercase().starts_with(&prefix.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_matches_is_case_insensitive_prefix() {
+        assert!(name_matches("dead_code", "dead"));
+        assert!(name_matches("dead_code", "DEAD"));
+        assert!(!name_matches("dead_code", "code"));
+    }
+}
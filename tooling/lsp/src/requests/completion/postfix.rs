@@ -0,0 +1,79 @@
+use async_lsp::lsp_types::CompletionItemKind;
+use noirc_errors::Span;
+
+use super::{NodeFinder, completion_items::snippet_completion_item_replacing, name_matches};
+
+/// A postfix snippet offered after `<expr>.`: the label the user types to
+/// trigger it, and the snippet body with `$0` marking where the cursor lands
+/// and `{receiver}` marking where the receiver expression is substituted.
+struct PostfixSnippet {
+    label: &'static str,
+    snippet: &'static str,
+}
+
+const POSTFIX_SNIPPETS: &[PostfixSnippet] = &[
+    PostfixSnippet { label: "if", snippet: "if {receiver} {\n    $0\n}" },
+    PostfixSnippet { label: "while", snippet: "while {receiver} {\n    $0\n}" },
+    PostfixSnippet { label: "for", snippet: "for ${1:elem} in {receiver} {\n    $0\n}" },
+    PostfixSnippet { label: "let", snippet: "let $0 = {receiver};" },
+    PostfixSnippet { label: "assert", snippet: "assert({receiver});" },
+    PostfixSnippet { label: "not", snippet: "!{receiver}" },
+    PostfixSnippet { label: "ref", snippet: "&{receiver}" },
+    PostfixSnippet { label: "refm", snippet: "&mut {receiver}" },
+];
+
+/// Substitutes the `{receiver}` placeholder in a postfix snippet body,
+/// leaving the rest of the snippet (including `$0`/`${1:..}` tabstops)
+/// untouched.
+fn render_postfix_snippet(snippet: &str, receiver_text: &str) -> String {
+    snippet.replace("{receiver}", receiver_text)
+}
+
+impl NodeFinder<'_> {
+    pub(super) fn postfix_completion(
+        &mut self,
+        receiver_text: &str,
+        receiver_and_method_span: Span,
+        prefix: &str,
+    ) {
+        for PostfixSnippet { label, snippet } in POSTFIX_SNIPPETS {
+            if !name_matches(label, prefix) {
+                continue;
+            }
+
+            let new_text = render_postfix_snippet(snippet, receiver_text);
+
+            // `receiver_and_method_span` covers the whole `receiver.postfix_name`
+            // text, so a single edit replaces it outright rather than an
+            // insert plus an overlapping additional edit (`additionalTextEdits`
+            // must not overlap the item's own edit range per the LSP spec).
+            let item = snippet_completion_item_replacing(
+                format!(".{label}"),
+                CompletionItemKind::SNIPPET,
+                self.span_to_range(receiver_and_method_span),
+                new_text,
+                None,
+            );
+
+            self.completion_items.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_if_snippet_without_doubled_braces() {
+        let rendered = render_postfix_snippet("if {receiver} {\n    $0\n}", "foo()");
+        assert_eq!(rendered, "if foo() {\n    $0\n}");
+    }
+
+    #[test]
+    fn renders_for_snippet_with_valid_tabstop() {
+        let rendered =
+            render_postfix_snippet("for ${1:elem} in {receiver} {\n    $0\n}", "items");
+        assert_eq!(rendered, "for ${1:elem} in items {\n    $0\n}");
+    }
+}
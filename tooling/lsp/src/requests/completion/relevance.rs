@@ -0,0 +1,67 @@
+/// Signals used to rank completion items by how well they fit the cursor
+/// position; higher-scoring items get a numerically smaller `sortText`.
+#[derive(Debug, Default, Clone, Copy)]
+pub(super) struct CompletionRelevance {
+    /// The item's type is exactly the type expected at the cursor (an
+    /// assignment RHS, an argument position, a return expression, etc.).
+    pub(super) exact_type_match: bool,
+    /// The item's name matches the prefix exactly rather than as a prefix
+    /// of a longer name.
+    pub(super) exact_name_match: bool,
+    /// The item is a local binding rather than an imported or builtin one.
+    pub(super) is_local: bool,
+    /// The item is the `self` parameter.
+    pub(super) is_self: bool,
+    /// The item is marked `#[deprecated]`.
+    pub(super) is_deprecated: bool,
+}
+
+impl CompletionRelevance {
+    /// Higher is better. Deprecated items are penalized below everything
+    /// else regardless of their other signals.
+    fn score(self) -> i32 {
+        if self.is_deprecated {
+            return -1;
+        }
+
+        let mut score = 0;
+        score += self.exact_type_match as i32 * 8;
+        score += self.exact_name_match as i32 * 4;
+        score += self.is_local as i32 * 2;
+        score += self.is_self as i32;
+        score
+    }
+
+    /// A zero-padded `sortText` that orders higher-scoring items first
+    /// while keeping ties in `label`'s own alphabetical order.
+    pub(super) fn sort_text(self, label: &str) -> String {
+        // Scores range roughly -1..=15; shift so the padded width never
+        // needs a sign and higher scores sort first.
+        let rank = 16 - (self.score() + 1);
+        format!("{rank:02}_{label}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_type_match_sorts_before_prefix_only_match() {
+        let exact = CompletionRelevance { exact_type_match: true, ..Default::default() };
+        let prefix_only = CompletionRelevance::default();
+        assert!(exact.sort_text("true") < prefix_only.sort_text("true"));
+    }
+
+    #[test]
+    fn deprecated_sorts_last_regardless_of_other_signals() {
+        let deprecated = CompletionRelevance {
+            exact_type_match: true,
+            exact_name_match: true,
+            is_deprecated: true,
+            ..Default::default()
+        };
+        let plain = CompletionRelevance::default();
+        assert!(deprecated.sort_text("foo") > plain.sort_text("foo"));
+    }
+}
@@ -0,0 +1,151 @@
+use async_lsp::lsp_types::CompletionItemKind;
+use noirc_frontend::ast::ItemVisibility;
+use noirc_frontend::hir::type_check::generics::TraitConstraint;
+use noirc_frontend::hir_def::traits::{Trait, TraitFunction};
+
+use super::{NodeFinder, completion_items::snippet_completion_item};
+
+impl NodeFinder<'_> {
+    pub(super) fn trait_impl_completion(&mut self, trait_: &Trait, already_implemented: &[String]) {
+        for method in &trait_.methods {
+            if already_implemented.contains(&method.name.to_string()) {
+                continue;
+            }
+            self.completion_items.push(snippet_completion_item(
+                method.name.to_string(),
+                CompletionItemKind::FUNCTION,
+                format!("{} {{\n    $0\n}}", render_method_signature(method)),
+                Some("trait method".to_string()),
+            ));
+        }
+
+        for constant in &trait_.associated_constants {
+            if already_implemented.contains(&constant.name.to_string()) {
+                continue;
+            }
+            self.completion_items.push(snippet_completion_item(
+                constant.name.to_string(),
+                CompletionItemKind::CONSTANT,
+                format!("let {}: {} = $0;", constant.name, constant.typ),
+                Some("trait constant".to_string()),
+            ));
+        }
+
+        for associated_type in &trait_.associated_types {
+            if already_implemented.contains(&associated_type.name.to_string()) {
+                continue;
+            }
+            self.completion_items.push(snippet_completion_item(
+                associated_type.name.to_string(),
+                CompletionItemKind::TYPE_PARAMETER,
+                format!("type {} = $0;", associated_type.name),
+                Some("trait associated type".to_string()),
+            ));
+        }
+    }
+}
+
+fn render_visibility(visibility: ItemVisibility) -> &'static str {
+    match visibility {
+        ItemVisibility::Public => "pub ",
+        ItemVisibility::PublicCrate => "pub(crate) ",
+        ItemVisibility::Private => "",
+    }
+}
+
+fn render_generics(generics: &[String]) -> String {
+    if generics.is_empty() { String::new() } else { format!("<{}>", generics.join(", ")) }
+}
+
+/// The plain-data pieces a rendered method signature is assembled from,
+/// kept separate from [`TraitFunction`] so the assembly logic can be unit
+/// tested without constructing a full compiler-internal trait method.
+struct SignatureParts<'a> {
+    visibility: &'a str,
+    name: &'a str,
+    generics: String,
+    params: &'a str,
+    return_type: Option<&'a str>,
+    where_clause: &'a str,
+}
+
+fn render_signature(parts: &SignatureParts<'_>) -> String {
+    let mut signature =
+        format!("{}fn {}{}({})", parts.visibility, parts.name, parts.generics, parts.params);
+
+    if let Some(return_type) = parts.return_type {
+        signature.push_str(" -> ");
+        signature.push_str(return_type);
+    }
+
+    if !parts.where_clause.is_empty() {
+        signature.push_str(" where ");
+        signature.push_str(parts.where_clause);
+    }
+
+    signature
+}
+
+/// Renders a trait method's declared signature (visibility, generics,
+/// parameters, return type and where-clause) faithfully, so the stub reads
+/// like hand-written code rather than a stripped-down approximation.
+fn render_method_signature(method: &TraitFunction) -> String {
+    let generics =
+        method.generics.iter().map(|generic| generic.name.to_string()).collect::<Vec<_>>();
+
+    let params = method
+        .arguments
+        .iter()
+        .map(|(pattern, typ)| format!("{pattern}: {typ}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let where_clause = method
+        .where_clause
+        .iter()
+        .map(TraitConstraint::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let return_type = method.return_type.as_ref().map(ToString::to_string);
+
+    render_signature(&SignatureParts {
+        visibility: render_visibility(method.visibility),
+        name: method.name.as_str(),
+        generics: render_generics(&generics),
+        params: &params,
+        return_type: return_type.as_deref(),
+        where_clause: &where_clause,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_visibility_and_generics() {
+        let rendered = render_signature(&SignatureParts {
+            visibility: "pub ",
+            name: "foo",
+            generics: "<T: Bar>".to_string(),
+            params: "self, x: Field",
+            return_type: Some("Field"),
+            where_clause: "",
+        });
+        assert_eq!(rendered, "pub fn foo<T: Bar>(self, x: Field) -> Field");
+    }
+
+    #[test]
+    fn renders_where_clause_when_present() {
+        let rendered = render_signature(&SignatureParts {
+            visibility: "",
+            name: "foo",
+            generics: String::new(),
+            params: "x: T",
+            return_type: None,
+            where_clause: "T: Bar",
+        });
+        assert_eq!(rendered, "fn foo(x: T) where T: Bar");
+    }
+}